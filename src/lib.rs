@@ -32,7 +32,7 @@
 //! can help:
 //!
 //! ``` rust
-//! # use core::num::{NonZeroU8, NonZeroU32};
+//! # use core::num::{NonZeroU8, NonZeroU32, NonZeroI32};
 //! # use nonzero_ext::{NonZeroAble};
 //! fn only_nonzeros<I>(v: Vec<I>) -> Vec<I::NonZero>
 //! where
@@ -54,11 +54,19 @@
 //! let input_u32: Vec<u32> = vec![0, 20, 5];
 //! let expected_u32: Vec<NonZeroU32> = vec![NonZeroU32::new(20).unwrap(), NonZeroU32::new(5).unwrap()];
 //! assert_eq!(expected_u32, only_nonzeros(input_u32));
+//!
+//! // And it works just as well for signed types like `i32`:
+//! let input_i32: Vec<i32> = vec![0, -20, 5];
+//! let expected_i32: Vec<NonZeroI32> = vec![NonZeroI32::new(-20).unwrap(), NonZeroI32::new(5).unwrap()];
+//! assert_eq!(expected_i32, only_nonzeros(input_i32));
 //! ```
 //!
 
-use core::num::NonZeroUsize;
-use core::num::{NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8};
+use core::num::{NonZeroIsize, NonZeroUsize};
+use core::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroU128, NonZeroU16,
+    NonZeroU32, NonZeroU64, NonZeroU8,
+};
 
 macro_rules! impl_nonzeroness {
     ($trait_name:ident, $nonzero_type:ty, $wrapped:ty) => {
@@ -70,6 +78,11 @@ macro_rules! impl_nonzeroness {
                 Self::new(n)
             }
 
+            #[inline]
+            unsafe fn new_unchecked(n: $wrapped) -> Self {
+                <$nonzero_type>::new_unchecked(n)
+            }
+
             #[inline]
             fn get(self) -> Self::Primitive {
                 <$nonzero_type>::get(self)
@@ -91,6 +104,16 @@ pub trait NonZero {
     where
         Self: Sized;
 
+    /// Creates a new non-zero object without checking whether `n` is
+    /// non-zero.
+    ///
+    /// # Safety
+    ///
+    /// `n` must not be zero.
+    unsafe fn new_unchecked(n: Self::Primitive) -> Self
+    where
+        Self: Sized;
+
     /// Returns the value as a primitive type.
     fn get(self) -> Self::Primitive;
 }
@@ -101,14 +124,48 @@ impl_nonzeroness!(NonZero, NonZeroU32, u32);
 impl_nonzeroness!(NonZero, NonZeroU64, u64);
 impl_nonzeroness!(NonZero, NonZeroU128, u128);
 impl_nonzeroness!(NonZero, NonZeroUsize, usize);
+impl_nonzeroness!(NonZero, NonZeroI8, i8);
+impl_nonzeroness!(NonZero, NonZeroI16, i16);
+impl_nonzeroness!(NonZero, NonZeroI32, i32);
+impl_nonzeroness!(NonZero, NonZeroI64, i64);
+impl_nonzeroness!(NonZero, NonZeroI128, i128);
+impl_nonzeroness!(NonZero, NonZeroIsize, isize);
+
+mod sealed {
+    pub trait Sealed {}
+}
 
 /// A trait identifying integral types that have a non-zeroable
 /// equivalent.
+///
+/// This trait is implemented for all of Rust's built-in integer
+/// primitives, but unlike [`ZeroablePrimitive`] it isn't sealed:
+/// downstream crates may implement it for their own integer-like
+/// wrapper types.
 pub trait NonZeroAble {
     /// The concrete non-zero type represented by an implementation of
     /// this trait. For example, for `u8`'s implementation, it is
-    /// `NonZeroU8`.
-    type NonZero: crate::NonZero;
+    /// `NonZeroU8`. Tying `Primitive` back to `Self` here is what
+    /// lets code generic over `P: NonZeroAble` call through to
+    /// `P::NonZero`'s methods without the compiler losing track of
+    /// which primitive type they operate on.
+    type NonZero: crate::NonZero<Primitive = Self>;
+
+    /// The zero value of this primitive type. Useful for generic code
+    /// that needs to compare against or construct a zero of `Self`
+    /// without naming the concrete primitive type.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// # use nonzero_ext::NonZeroAble;
+    /// fn is_zero<P: NonZeroAble + PartialEq>(n: P) -> bool {
+    ///   n == P::ZERO
+    /// }
+    /// assert!(is_zero(0u32));
+    /// assert!(!is_zero(1u32));
+    /// ```
+    const ZERO: Self;
 
     /// Converts the integer to its non-zero equivalent.
     ///
@@ -130,16 +187,83 @@ pub trait NonZeroAble {
     /// assert_eq!(non0n.get(), 20);
     /// ```
     fn as_nonzero(self) -> Option<Self::NonZero>;
+
+    /// Parses a string slice in the given radix into the
+    /// width-appropriate non-zero type.
+    ///
+    /// This mirrors the standard library's per-type `from_str_radix`,
+    /// but is usable in code that is generic over `Self: NonZeroAble`
+    /// rather than a concrete primitive. Returns
+    /// [`NonZeroParseError::InvalidRadix`] if `radix` is outside
+    /// `2..=36` (unlike `from_str_radix`, which panics in that case —
+    /// this keeps the method safe to call with an untrusted or
+    /// misconfigured radix), [`NonZeroParseError::Invalid`] if `s`
+    /// isn't a valid digit string in `radix`, or
+    /// [`NonZeroParseError::Zero`] if it parses fine but is zero.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// # use nonzero_ext::{NonZeroAble, NonZeroParseError};
+    /// # use core::num::NonZeroU8;
+    /// assert_eq!(u8::parse_nonzero("2a", 16), Ok(NonZeroU8::new(42).unwrap()));
+    /// assert_eq!(u8::parse_nonzero("0", 10), Err(NonZeroParseError::Zero));
+    /// assert!(matches!(u8::parse_nonzero("nope", 10), Err(NonZeroParseError::Invalid(_))));
+    /// assert_eq!(u8::parse_nonzero("5", 37), Err(NonZeroParseError::InvalidRadix(37)));
+    /// ```
+    fn parse_nonzero(s: &str, radix: u32) -> Result<Self::NonZero, NonZeroParseError>;
+}
+
+/// An error returned by [`NonZeroAble::parse_nonzero`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NonZeroParseError {
+    /// The string was parsed successfully, but the resulting value
+    /// was zero.
+    Zero,
+    /// The string could not be parsed as the underlying primitive
+    /// type, e.g. because it contained invalid digits for the radix
+    /// or the value overflowed the primitive's range.
+    Invalid(core::num::ParseIntError),
+    /// `radix` was outside the `2..=36` range that
+    /// `from_str_radix` accepts. Checked up front so that a bad
+    /// (e.g. config-supplied) radix yields an `Err` instead of
+    /// panicking.
+    InvalidRadix(u32),
+}
+
+impl core::fmt::Display for NonZeroParseError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            NonZeroParseError::Zero => write!(f, "value was zero"),
+            NonZeroParseError::Invalid(e) => core::fmt::Display::fmt(e, f),
+            NonZeroParseError::InvalidRadix(radix) => {
+                write!(f, "radix {} is outside the supported range of 2..=36", radix)
+            }
+        }
+    }
 }
 
 macro_rules! impl_nonzeroable {
     ($trait_name:ident, $nonzero_type: ty, $nonzeroable_type:ty) => {
+        impl sealed::Sealed for $nonzeroable_type {}
+
         impl $trait_name for $nonzeroable_type {
             type NonZero = $nonzero_type;
 
+            const ZERO: Self = 0;
+
             fn as_nonzero(self) -> Option<$nonzero_type> {
                 Self::NonZero::new(self)
             }
+
+            fn parse_nonzero(s: &str, radix: u32) -> Result<Self::NonZero, NonZeroParseError> {
+                if !(2..=36).contains(&radix) {
+                    return Err(NonZeroParseError::InvalidRadix(radix));
+                }
+                let n = <$nonzeroable_type>::from_str_radix(s, radix)
+                    .map_err(NonZeroParseError::Invalid)?;
+                Self::NonZero::new(n).ok_or(NonZeroParseError::Zero)
+            }
         }
     };
 }
@@ -149,4 +273,253 @@ impl_nonzeroable!(NonZeroAble, NonZeroU16, u16);
 impl_nonzeroable!(NonZeroAble, NonZeroU32, u32);
 impl_nonzeroable!(NonZeroAble, NonZeroU64, u64);
 impl_nonzeroable!(NonZeroAble, NonZeroU128, u128);
-impl_nonzeroable!(NonZeroAble, NonZeroUsize, usize);
\ No newline at end of file
+impl_nonzeroable!(NonZeroAble, NonZeroUsize, usize);
+impl_nonzeroable!(NonZeroAble, NonZeroI8, i8);
+impl_nonzeroable!(NonZeroAble, NonZeroI16, i16);
+impl_nonzeroable!(NonZeroAble, NonZeroI32, i32);
+impl_nonzeroable!(NonZeroAble, NonZeroI64, i64);
+impl_nonzeroable!(NonZeroAble, NonZeroI128, i128);
+impl_nonzeroable!(NonZeroAble, NonZeroIsize, isize);
+
+/// A trait offering checked and saturating arithmetic over non-zero
+/// integral types, for generic code that needs to keep a value
+/// non-zero across a sequence of operations (e.g. a sparse map that
+/// treats zero as "absent").
+///
+/// Checked operations delegate to the underlying primitive's checked
+/// arithmetic and then re-wrap the result through [`NonZero::new`],
+/// so a result of zero (as opposed to an overflow) also yields
+/// `None`. Saturating operations can't return `None`, so a result of
+/// zero saturates to the nearest non-zero value in the direction of
+/// `self`'s sign instead.
+pub trait NonZeroArithmetic: crate::NonZero
+where
+    Self: Sized,
+{
+    /// Adds `rhs` to `self`, returning `None` if the result overflows
+    /// the primitive type or is zero.
+    ///
+    /// This is named `checked_add_primitive` rather than `checked_add`
+    /// for the same reason as
+    /// [`NonZeroArithmetic::checked_mul_primitive`]: the standard
+    /// library's unsigned `NonZero<T>` types already have an inherent
+    /// `checked_add` taking a primitive `rhs`, which would otherwise
+    /// shadow this method via `n.checked_add(rhs)`.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// # use nonzero_ext::NonZeroArithmetic;
+    /// # use core::num::NonZeroU8;
+    /// let n = NonZeroU8::new(5).unwrap();
+    /// assert_eq!(n.checked_add_primitive(3), NonZeroU8::new(8));
+    /// assert_eq!(n.checked_add_primitive(u8::MAX), None);
+    /// ```
+    fn checked_add_primitive(self, rhs: Self::Primitive) -> Option<Self>;
+
+    /// Multiplies `self` by `rhs`, returning `None` if the result
+    /// overflows the primitive type or is zero.
+    ///
+    /// This is named `checked_mul_primitive` rather than `checked_mul`
+    /// because the standard library's `NonZero<T>` already has an
+    /// inherent `checked_mul` that takes another `NonZero<T>` as
+    /// `rhs` (multiplying two non-zeros can only overflow, never
+    /// become zero). Inherent methods always win over trait methods
+    /// in method-call syntax, so keeping the name `checked_mul` here
+    /// would make this method unreachable via `n.checked_mul(rhs)`.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// # use nonzero_ext::NonZeroArithmetic;
+    /// # use core::num::NonZeroU8;
+    /// let n = NonZeroU8::new(5).unwrap();
+    /// assert_eq!(n.checked_mul_primitive(3), NonZeroU8::new(15));
+    /// assert_eq!(n.checked_mul_primitive(u8::MAX), None);
+    /// ```
+    fn checked_mul_primitive(self, rhs: Self::Primitive) -> Option<Self>;
+
+    /// Subtracts `rhs` from `self`, returning `None` if the result
+    /// overflows the primitive type or is zero.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// # use nonzero_ext::NonZeroArithmetic;
+    /// # use core::num::NonZeroU8;
+    /// let n = NonZeroU8::new(5).unwrap();
+    /// assert_eq!(n.checked_sub(3), NonZeroU8::new(2));
+    /// assert_eq!(n.checked_sub(5), None);
+    /// assert_eq!(n.checked_sub(10), None);
+    /// ```
+    fn checked_sub(self, rhs: Self::Primitive) -> Option<Self>;
+
+    /// Adds `rhs` to `self`, saturating at the primitive type's
+    /// bounds. If the exact result would be zero, saturates to the
+    /// nearest non-zero value instead.
+    ///
+    /// Named `saturating_add_primitive` for the same reason as
+    /// [`NonZeroArithmetic::checked_add_primitive`]: the standard
+    /// library's unsigned `NonZero<T>` types already have an inherent
+    /// `saturating_add` taking a primitive `rhs`, which would
+    /// otherwise shadow this method entirely.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// # use nonzero_ext::NonZeroArithmetic;
+    /// # use core::num::NonZeroU8;
+    /// let n = NonZeroU8::new(5).unwrap();
+    /// assert_eq!(n.saturating_add_primitive(3), NonZeroU8::new(8).unwrap());
+    /// assert_eq!(n.saturating_add_primitive(u8::MAX), NonZeroU8::new(u8::MAX).unwrap());
+    /// ```
+    fn saturating_add_primitive(self, rhs: Self::Primitive) -> Self;
+
+    /// Multiplies `self` by `rhs`, saturating at the primitive type's
+    /// bounds. If the exact result would be zero, saturates to the
+    /// nearest non-zero value instead.
+    ///
+    /// Named `saturating_mul_primitive` for the same reason as
+    /// [`NonZeroArithmetic::checked_mul_primitive`]: the standard
+    /// library's `NonZero<T>` already has an inherent `saturating_mul`
+    /// taking another `NonZero<T>`, which would otherwise shadow this
+    /// method entirely.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// # use nonzero_ext::NonZeroArithmetic;
+    /// # use core::num::{NonZeroU8, NonZeroI8};
+    /// let n = NonZeroU8::new(5).unwrap();
+    /// assert_eq!(n.saturating_mul_primitive(3), NonZeroU8::new(15).unwrap());
+    /// assert_eq!(n.saturating_mul_primitive(u8::MAX), NonZeroU8::new(u8::MAX).unwrap());
+    ///
+    /// // A negative value saturates towards negative infinity, not zero.
+    /// let neg = NonZeroI8::new(-5).unwrap();
+    /// assert_eq!(neg.saturating_mul_primitive(i8::MAX), NonZeroI8::new(i8::MIN).unwrap());
+    /// ```
+    fn saturating_mul_primitive(self, rhs: Self::Primitive) -> Self;
+
+    /// Subtracts `rhs` from `self`, saturating at the primitive
+    /// type's bounds. If the exact result would be zero, saturates to
+    /// the nearest non-zero value instead.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// # use nonzero_ext::NonZeroArithmetic;
+    /// # use core::num::{NonZeroU8, NonZeroI8};
+    /// let n = NonZeroU8::new(5).unwrap();
+    /// assert_eq!(n.saturating_sub(3), NonZeroU8::new(2).unwrap());
+    ///
+    /// // An exact-zero result saturates to the nearest non-zero value
+    /// // in the direction of `self`'s sign instead of wrapping to zero.
+    /// assert_eq!(n.saturating_sub(5), NonZeroU8::new(1).unwrap());
+    ///
+    /// let neg = NonZeroI8::new(-5).unwrap();
+    /// assert_eq!(neg.saturating_sub(-5), NonZeroI8::new(-1).unwrap());
+    /// ```
+    fn saturating_sub(self, rhs: Self::Primitive) -> Self;
+}
+
+macro_rules! impl_nonzero_arithmetic {
+    ($nonzero_type:ty, $wrapped:ty, $nearest_nonzero:expr) => {
+        impl NonZeroArithmetic for $nonzero_type {
+            #[inline]
+            fn checked_add_primitive(self, rhs: $wrapped) -> Option<Self> {
+                self.get().checked_add(rhs).and_then(Self::new)
+            }
+
+            #[inline]
+            fn checked_mul_primitive(self, rhs: $wrapped) -> Option<Self> {
+                self.get().checked_mul(rhs).and_then(Self::new)
+            }
+
+            #[inline]
+            fn checked_sub(self, rhs: $wrapped) -> Option<Self> {
+                self.get().checked_sub(rhs).and_then(Self::new)
+            }
+
+            #[inline]
+            fn saturating_add_primitive(self, rhs: $wrapped) -> Self {
+                let nearest_nonzero: fn(Self) -> $wrapped = $nearest_nonzero;
+                Self::new(self.get().saturating_add(rhs))
+                    .unwrap_or_else(|| Self::new(nearest_nonzero(self)).unwrap())
+            }
+
+            #[inline]
+            fn saturating_mul_primitive(self, rhs: $wrapped) -> Self {
+                let nearest_nonzero: fn(Self) -> $wrapped = $nearest_nonzero;
+                Self::new(self.get().saturating_mul(rhs))
+                    .unwrap_or_else(|| Self::new(nearest_nonzero(self)).unwrap())
+            }
+
+            #[inline]
+            fn saturating_sub(self, rhs: $wrapped) -> Self {
+                let nearest_nonzero: fn(Self) -> $wrapped = $nearest_nonzero;
+                Self::new(self.get().saturating_sub(rhs))
+                    .unwrap_or_else(|| Self::new(nearest_nonzero(self)).unwrap())
+            }
+        }
+    };
+}
+
+impl_nonzero_arithmetic!(NonZeroU8, u8, |_| 1);
+impl_nonzero_arithmetic!(NonZeroU16, u16, |_| 1);
+impl_nonzero_arithmetic!(NonZeroU32, u32, |_| 1);
+impl_nonzero_arithmetic!(NonZeroU64, u64, |_| 1);
+impl_nonzero_arithmetic!(NonZeroU128, u128, |_| 1);
+impl_nonzero_arithmetic!(NonZeroUsize, usize, |_| 1);
+impl_nonzero_arithmetic!(NonZeroI8, i8, |n: NonZeroI8| if n.get() < 0 { -1 } else { 1 });
+impl_nonzero_arithmetic!(NonZeroI16, i16, |n: NonZeroI16| if n.get() < 0 { -1 } else { 1 });
+impl_nonzero_arithmetic!(NonZeroI32, i32, |n: NonZeroI32| if n.get() < 0 { -1 } else { 1 });
+impl_nonzero_arithmetic!(NonZeroI64, i64, |n: NonZeroI64| if n.get() < 0 { -1 } else { 1 });
+impl_nonzero_arithmetic!(NonZeroI128, i128, |n: NonZeroI128| if n.get() < 0 { -1 } else { 1 });
+impl_nonzero_arithmetic!(NonZeroIsize, isize, |n: NonZeroIsize| if n.get() < 0 { -1 } else { 1 });
+
+/// A primitive integer type with a corresponding non-zero type.
+///
+/// Unlike [`NonZeroAble`], this trait is sealed: it's blanket-implemented
+/// only for the built-in integer primitives that also implement the
+/// private `sealed::Sealed` marker, so it can't be implemented for a
+/// downstream crate's own `NonZeroAble` impl. That's what lets the free
+/// functions [`new`] and [`new_unchecked`] below rely on `P::NonZero`
+/// always being a genuine standard-library non-zero type, without
+/// forcing `NonZeroAble` itself to be sealed.
+pub trait ZeroablePrimitive: NonZeroAble + sealed::Sealed {}
+
+impl<P: NonZeroAble + sealed::Sealed> ZeroablePrimitive for P {}
+
+/// Creates a non-zero value of the width-appropriate type, returning
+/// `None` if `value` is zero.
+///
+/// This is a free-function equivalent of [`NonZeroAble::as_nonzero`]
+/// that's usable in generic contexts bounded only by `P:
+/// ZeroablePrimitive`, without needing to name the concrete `NonZeroU32`-style
+/// return type.
+///
+/// # Examples
+///
+/// ``` rust
+/// # use core::num::NonZeroU32;
+/// let n: Option<NonZeroU32> = nonzero_ext::new(5u32);
+/// assert_eq!(n, NonZeroU32::new(5));
+///
+/// let zero: Option<NonZeroU32> = nonzero_ext::new(0u32);
+/// assert_eq!(zero, None);
+/// ```
+#[inline]
+pub fn new<P: ZeroablePrimitive>(value: P) -> Option<P::NonZero> {
+    value.as_nonzero()
+}
+
+/// Creates a non-zero value of the width-appropriate type without
+/// checking whether `value` is zero.
+///
+/// # Safety
+///
+/// `value` must not be zero.
+#[inline]
+pub unsafe fn new_unchecked<P: ZeroablePrimitive>(value: P) -> P::NonZero {
+    P::NonZero::new_unchecked(value)
+}
\ No newline at end of file